@@ -1,26 +1,97 @@
 use std::path::PathBuf;
 use std::process::{Command, Stdio};
-use std::fs::File;
-use std::io::{Read, BufReader, BufRead};
+use std::fs::{self, File};
+use std::io::{Read, Write, BufReader, BufRead};
+use flate2::{Compression, GzBuilder};
 
-/// Generates and prints a manpage for the given binary to the screen.
+/// Generates a manpage for the given binary and writes it to `output_dir`.
 ///
 /// # Arguments
 /// * `binary_path` - Path to the binary for which the manpage is being generated.
 /// * `name` - Name of the binary (used in the manpage header).
+/// * `output_dir` - Directory the manpage will be written to, as `<name>.<section>` (or
+///   `<name>.<section>.gz` when `gzip` is set).
 /// * `section` - Section number of the manpage (e.g., 1 for general commands, 2 for system calls).
 /// * `title` - Title of the manpage (e.g., "General commands").
+/// * `gzip` - When `true`, gzip-compress the written manpage instead of writing plain roff.
+/// * `stdout` - When `true`, print the manpage to the screen instead of writing a file.
 ///
 /// # Returns
 /// * `Ok(())` if the manpage is successfully generated.
 /// * `Err(String)` containing an error message if the generation fails.
-pub fn generate_manpage(binary_path: &PathBuf, name: &str, section: u8, title: &str) -> Result<(), String> {
+pub fn generate_manpage(
+    binary_path: &PathBuf,
+    name: &str,
+    output_dir: &PathBuf,
+    section: u8,
+    title: &str,
+    gzip: bool,
+    stdout: bool,
+) -> Result<(), String> {
 
     // Generate manpage content
     let manpage_content = generate_manpage_content(binary_path, name, section, title)?;
 
-    // Print manpage content to the screen
-    println!("{}", manpage_content);
+    if stdout {
+        // Print manpage content to the screen
+        println!("{}", manpage_content);
+        return Ok(());
+    }
+
+    write_manpage_file(&manpage_content, name, output_dir, section, gzip)
+}
+
+/// Writes the generated manpage content to `<output_dir>/<name>.<section>`, optionally
+/// gzip-compressing it to `<output_dir>/<name>.<section>.gz`.
+///
+/// The gzip header's comment field records the uncompressed byte length of the roff, mirroring
+/// how other tools embed the original length before writing the compressed bytes, so that
+/// downstream `man`/`mandoc` loaders that read the comment can size their buffers up front.
+///
+/// # Arguments
+/// * `manpage_content` - The roff content to write.
+/// * `name` - Name of the binary (used as the manpage file's base name).
+/// * `output_dir` - Directory the manpage will be written to.
+/// * `section` - Section number of the manpage (used as the file's extension).
+/// * `gzip` - When `true`, gzip-compress the file instead of writing plain roff.
+///
+/// # Returns
+/// * `Ok(())` if the file is successfully written.
+/// * `Err(String)` containing an error message if the write fails.
+fn write_manpage_file(
+    manpage_content: &str,
+    name: &str,
+    output_dir: &PathBuf,
+    section: u8,
+    gzip: bool,
+) -> Result<(), String> {
+    if name.contains('/') || name.contains('\\') {
+        return Err(format!("Invalid manpage name \"{}\": must not contain path separators", name));
+    }
+    let file_name = format!("{}.{}", name, section);
+
+    if gzip {
+        let gz_path = output_dir.join(format!("{}.gz", file_name));
+        let file = File::create(&gz_path)
+            .map_err(|e| format!("Failed to create {}: {}", gz_path.display(), e))?;
+
+        let uncompressed_len = manpage_content.len();
+        let mut encoder = GzBuilder::new()
+            .comment(uncompressed_len.to_string())
+            .write(file, Compression::default());
+
+        encoder
+            .write_all(manpage_content.as_bytes())
+            .map_err(|e| format!("Failed to write gzipped manpage: {}", e))?;
+        encoder
+            .finish()
+            .map_err(|e| format!("Failed to finalize gzipped manpage: {}", e))?;
+    } else {
+        let output_path = output_dir.join(&file_name);
+        fs::write(&output_path, manpage_content)
+            .map_err(|e| format!("Failed to write {}: {}", output_path.display(), e))?;
+    }
+
     Ok(())
 }
 
@@ -24,6 +24,14 @@ struct Args {
     /// Title of the manual (default depends on the section)
     #[arg(short = 't', long)]
     title: Option<String>,
+
+    /// Gzip-compress the generated manpage instead of writing plain roff
+    #[arg(long, conflicts_with = "stdout")]
+    gzip: bool,
+
+    /// Print the generated manpage to stdout instead of writing it to a file
+    #[arg(long)]
+    stdout: bool,
 }
 
 fn main() {
@@ -56,7 +64,15 @@ fn main() {
             .to_string()
     });
 
-    if let Err(e) = generate_manpage(&args.binary_path, &binary_name, &args.output_dir, args.section, &title) {
+    if let Err(e) = generate_manpage(
+        &args.binary_path,
+        &binary_name,
+        &args.output_dir,
+        args.section,
+        &title,
+        args.gzip,
+        args.stdout,
+    ) {
         eprintln!("Error: {}", e);
         std::process::exit(1);
     }